@@ -1,49 +1,599 @@
 #!/usr/bin/env rust-script
 
-//! Session cache reader for design-source methodology projects.
+//! Session cache reader and lifecycle manager for design-source
+//! methodology projects.
 //!
-//! This script reads and displays the session cache to help
-//! restore context between AI assistant sessions.
+//! This script reads and displays the session cache to help restore
+//! context between AI assistant sessions, and manages the lifecycle of
+//! those sessions (`begin`/`pause`/`resume`/`end`) so cumulative time
+//! spent per phase can be tracked across invocations.
 //!
 //! ## Usage
 //!
 //! ```bash
-//! rust-script .claude/scripts/read_cache.rs
+//! rust-script .claude/scripts/read_cache.rs [status|begin|pause|resume|end|watch] [--sync-tasks]
 //! ```
+//!
+//! With no subcommand, defaults to `status` (the original read-only
+//! behavior). `status --sync-tasks` additionally reconciles
+//! `pending_tasks` against the Git history since the last session.
+//! `watch` runs as a long-lived daemon that keeps the cache's file
+//! snapshot live, the way an LSP server keeps its view of open
+//! documents up to date.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
+use std::process::Command;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+const CACHE_PATH: &str = ".claude/cache/session.json";
 
 fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let subcommand = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .cloned()
+        .unwrap_or_else(|| "status".to_string());
+    let sync_tasks = args.iter().any(|a| a == "--sync-tasks");
+
+    match subcommand.as_str() {
+        "status" => cmd_status(sync_tasks),
+        "begin" => cmd_begin(),
+        "pause" => cmd_pause(),
+        "resume" => cmd_resume(),
+        "end" => cmd_end(),
+        "watch" => cmd_watch(),
+        other => {
+            println!("❌ Unknown subcommand: {}", other);
+            println!("💡 Usage: read_cache.rs [status|begin|pause|resume|end|watch] [--sync-tasks]");
+        }
+    }
+}
+
+/// Loads the cache, prints it banner-first, and falls through to
+/// [`display_cache`]. This is the original one-way reader, now exposed
+/// as the `status` subcommand. When `sync_tasks` is set, also reconciles
+/// `pending_tasks` against Git activity since the last session.
+fn cmd_status(sync_tasks: bool) {
     println!("🔍 Loading previous conversation context...");
     println!();
 
-    let cache_path = ".claude/cache/session.json";
-
-    if !Path::new(cache_path).exists() {
+    if !Path::new(CACHE_PATH).exists() {
         println!("❌ No previous conversation found");
         println!("💡 Tip: Run /save-session-cache to create a cache for this project");
         return;
     }
 
-    match fs::read_to_string(cache_path) {
-        Ok(content) => {
+    match load_cache() {
+        Ok(mut cache) => {
             println!("✅ Context loaded successfully!");
             println!();
+            check_freshness(&cache);
 
-            // Parse and display cache content
-            if let Ok(cache) = serde_json::from_str::<serde_json::Value>(&content) {
-                display_cache(&cache);
-            } else {
-                println!("⚠️  Cache file exists but couldn't be parsed");
+            // Resolve and apply any --sync-tasks reconciliation before
+            // display_cache renders pending_tasks, so the summary and the
+            // "since last session" section never disagree about its contents.
+            let commits = commits_since_last_session(&cache);
+            if sync_tasks {
+                sync_pending_tasks(&mut cache, &commits);
             }
+
+            display_cache(&cache);
+            show_since_last_session(&commits);
         }
         Err(e) => {
-            println!("❌ Failed to read cache: {}", e);
+            println!("⚠️  {}", e);
         }
     }
 }
 
+/// Fetches the commits, changed files, and authors from `git log` since
+/// `session.last_timestamp`, for both the "since last session" banner
+/// and `--sync-tasks` reconciliation to share.
+fn commits_since_last_session(cache: &serde_json::Value) -> Vec<CommitInfo> {
+    let Some(last_timestamp) = cache["session"]["last_timestamp"].as_str() else {
+        return Vec::new();
+    };
+    git_log_since(last_timestamp)
+}
+
+/// Renders the "Since last session" section: commits, changed files, and
+/// authors since the previous session ended.
+fn show_since_last_session(commits: &[CommitInfo]) {
+    if commits.is_empty() {
+        return;
+    }
+
+    println!("🧭 Since last session:");
+    for commit in commits {
+        println!("  {} {} — {}", &commit.hash[..7.min(commit.hash.len())], commit.author, commit.subject);
+        for file in &commit.files {
+            println!("      {}", file);
+        }
+    }
+    println!();
+}
+
+struct CommitInfo {
+    hash: String,
+    author: String,
+    subject: String,
+    files: Vec<String>,
+}
+
+/// Runs `git log --since=<timestamp>` and parses commit hash, author,
+/// subject, and changed files out of the output.
+fn git_log_since(timestamp: &str) -> Vec<CommitInfo> {
+    let since_arg = match timestamp.parse::<u64>() {
+        Ok(secs) => format!("@{}", secs),
+        Err(_) => timestamp.to_string(),
+    };
+
+    let output = Command::new("git")
+        .args([
+            "log",
+            &format!("--since={}", since_arg),
+            "--pretty=format:%x02%H%x1f%an%x1f%s",
+            "--name-only",
+        ])
+        .output();
+
+    let Ok(output) = output else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut commits = Vec::new();
+
+    for record in text.split('\u{2}').filter(|r| !r.trim().is_empty()) {
+        let mut lines = record.lines();
+        let Some(header) = lines.next() else { continue };
+        let mut parts = header.splitn(3, '\u{1f}');
+        let (Some(hash), Some(author), Some(subject)) = (parts.next(), parts.next(), parts.next()) else {
+            continue;
+        };
+        let files = lines.filter(|l| !l.trim().is_empty()).map(str::to_string).collect();
+
+        commits.push(CommitInfo {
+            hash: hash.to_string(),
+            author: author.to_string(),
+            subject: subject.to_string(),
+            files,
+        });
+    }
+
+    commits
+}
+
+/// Checks whether `desc` references issue number `digits` as a whole
+/// token (`#12`), not merely as a substring of a longer number
+/// (`#120`) or another issue reference (`#128`).
+fn references_issue(desc: &str, digits: &str) -> bool {
+    let needle = format!("#{}", digits);
+    let mut search_from = 0;
+    while let Some(offset) = desc[search_from..].find(&needle) {
+        let match_end = search_from + offset + needle.len();
+        let at_boundary = desc.as_bytes().get(match_end).is_none_or(|b| !b.is_ascii_digit());
+        if at_boundary {
+            return true;
+        }
+        search_from = match_end;
+    }
+    false
+}
+
+/// Auto-removes pending tasks whose referenced issue was closed (`fixes
+/// #N` in a commit subject), and surfaces new `WIP` items as pending
+/// tasks, so `pending_tasks` stays aligned with what actually happened
+/// in the repo between AI sessions.
+fn sync_pending_tasks(cache: &mut serde_json::Value, commits: &[CommitInfo]) {
+    let mut closed_issues: Vec<String> = Vec::new();
+    let mut wip_items: Vec<String> = Vec::new();
+
+    for commit in commits {
+        let subject = &commit.subject;
+        if let Some(idx) = subject.to_lowercase().find("fixes #") {
+            let rest = &subject[idx + "fixes #".len()..];
+            let digits: String = rest.chars().take_while(|c| c.is_ascii_digit()).collect();
+            if !digits.is_empty() {
+                closed_issues.push(digits);
+            }
+        }
+        if subject.contains("WIP") {
+            wip_items.push(subject.clone());
+        }
+    }
+
+    if !cache["pending_tasks"].is_array() {
+        cache["pending_tasks"] = serde_json::json!([]);
+    }
+    let tasks = cache["pending_tasks"].as_array_mut().unwrap();
+
+    let before = tasks.len();
+    tasks.retain(|task| {
+        let Some(desc) = task.as_str() else { return true };
+        !closed_issues.iter().any(|issue| references_issue(desc, issue))
+    });
+    let removed = before - tasks.len();
+
+    let mut added = 0;
+    for item in &wip_items {
+        let already_present = tasks.iter().any(|t| t.as_str() == Some(item.as_str()));
+        if !already_present {
+            tasks.push(serde_json::json!(item));
+            added += 1;
+        }
+    }
+
+    if removed > 0 || added > 0 {
+        save_cache(cache);
+        println!(
+            "🔁 Synced pending tasks: {} closed, {} new WIP item(s)",
+            removed, added
+        );
+        println!();
+    }
+}
+
+/// Starts a new session: bumps `session.count`, stamps
+/// `session.last_timestamp`, and opens an `active_interval` so `pause`,
+/// `resume`, and `end` have something to close out.
+fn cmd_begin() {
+    let mut cache = load_cache_or_default();
+
+    if close_stale_interval(&mut cache) {
+        println!("⚠️  Previous session was never closed — auto-closed into session history");
+    }
+
+    let now = now_secs();
+    let count = cache["session"]["count"].as_u64().unwrap_or(0) + 1;
+    cache["session"]["count"] = serde_json::json!(count);
+    cache["session"]["last_timestamp"] = serde_json::json!(now.to_string());
+    cache["active_interval"] = serde_json::json!({
+        "started_at": now,
+        "paused_secs": 0,
+    });
+    cache["snapshot"] = build_snapshot(&tracked_paths());
+
+    save_cache(&cache);
+    println!("▶️  Session #{} started", count);
+}
+
+/// If an `active_interval` from a previous session was never closed by
+/// `end` (e.g. the assistant session was killed mid-work), appends it to
+/// `sessions` and clears it, so `begin` never silently clobbers an
+/// unclosed interval.
+fn close_stale_interval(cache: &mut serde_json::Value) -> bool {
+    let Some(started_at) = cache["active_interval"]["started_at"].as_u64() else {
+        return false;
+    };
+
+    let ended_at = now_secs();
+    let paused_secs = cache["active_interval"]["paused_secs"].as_u64().unwrap_or(0);
+    let record = serde_json::json!({
+        "started_at": started_at,
+        "ended_at": ended_at,
+        "paused_secs": paused_secs,
+    });
+
+    if !cache["sessions"].is_array() {
+        cache["sessions"] = serde_json::json!([]);
+    }
+    cache["sessions"].as_array_mut().unwrap().push(record);
+    cache["active_interval"] = serde_json::Value::Null;
+    true
+}
+
+/// Records the current time as the start of a pause within the active
+/// interval, so `resume` can compute how long the gap lasted.
+fn cmd_pause() {
+    let mut cache = load_cache_or_default();
+
+    if cache["active_interval"].is_null() {
+        println!("⚠️  No active session to pause. Run `begin` first.");
+        return;
+    }
+
+    cache["active_interval"]["paused_at"] = serde_json::json!(now_secs());
+    save_cache(&cache);
+    println!("⏸️  Session paused");
+}
+
+/// Closes out a pending pause, adding the elapsed gap to the active
+/// interval's `paused_secs` total.
+fn cmd_resume() {
+    let mut cache = load_cache_or_default();
+
+    let Some(paused_at) = cache["active_interval"]["paused_at"].as_u64() else {
+        println!("⚠️  No pause to resume from.");
+        return;
+    };
+
+    let gap = now_secs().saturating_sub(paused_at);
+    let paused_secs = cache["active_interval"]["paused_secs"].as_u64().unwrap_or(0);
+    cache["active_interval"]["paused_secs"] = serde_json::json!(paused_secs + gap);
+    cache["active_interval"]
+        .as_object_mut()
+        .unwrap()
+        .remove("paused_at");
+
+    save_cache(&cache);
+    println!("▶️  Session resumed");
+}
+
+/// Closes the active interval, appends a completed `sessions` record
+/// with the total active duration, and clears `active_interval`.
+fn cmd_end() {
+    let mut cache = load_cache_or_default();
+
+    let Some(started_at) = cache["active_interval"]["started_at"].as_u64() else {
+        println!("⚠️  No active session to end. Run `begin` first.");
+        return;
+    };
+
+    let ended_at = now_secs();
+    let paused_secs = cache["active_interval"]["paused_secs"].as_u64().unwrap_or(0);
+
+    let record = serde_json::json!({
+        "started_at": started_at,
+        "ended_at": ended_at,
+        "paused_secs": paused_secs,
+    });
+
+    if !cache["sessions"].is_array() {
+        cache["sessions"] = serde_json::json!([]);
+    }
+    cache["sessions"].as_array_mut().unwrap().push(record);
+    cache["active_interval"] = serde_json::Value::Null;
+    cache["session"]["last_timestamp"] = serde_json::json!(ended_at.to_string());
+    cache["snapshot"] = build_snapshot(&tracked_paths());
+
+    save_cache(&cache);
+
+    let active_secs = ended_at.saturating_sub(started_at).saturating_sub(paused_secs);
+    println!("⏹️  Session ended ({}s active)", active_secs);
+}
+
+fn now_secs() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+fn load_cache() -> Result<serde_json::Value, String> {
+    let content = fs::read_to_string(CACHE_PATH).map_err(|e| format!("Failed to read cache: {}", e))?;
+    serde_json::from_str(&content).map_err(|_| "Cache file exists but couldn't be parsed".to_string())
+}
+
+/// Loads the cache for a mutating subcommand, starting from an empty
+/// object if no cache file exists yet (so `begin` works on a fresh
+/// project).
+fn load_cache_or_default() -> serde_json::Value {
+    load_cache().unwrap_or_else(|_| serde_json::json!({}))
+}
+
+/// Writes the cache atomically: serialize to a process-unique sibling
+/// `.tmp` file, then rename it into place, so a watcher tick and a
+/// concurrent save never race on the same temp file or leave
+/// `session.json` half-written.
+fn save_cache(cache: &serde_json::Value) {
+    if let Some(parent) = Path::new(CACHE_PATH).parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    match serde_json::to_string_pretty(cache) {
+        Ok(content) => {
+            let tmp_path = format!("{}.{}.tmp", CACHE_PATH, std::process::id());
+            if let Err(e) = fs::write(&tmp_path, content) {
+                println!("❌ Failed to write cache: {}", e);
+                return;
+            }
+            if let Err(rename_err) = fs::rename(&tmp_path, CACHE_PATH) {
+                // Rename can fail across filesystem boundaries; fall back to a
+                // copy so the update isn't silently dropped.
+                let fallback = fs::copy(&tmp_path, CACHE_PATH).map(|_| ());
+                let _ = fs::remove_file(&tmp_path);
+                if let Err(copy_err) = fallback {
+                    println!(
+                        "❌ Failed to finalize cache write (rename: {}, copy: {})",
+                        rename_err, copy_err
+                    );
+                }
+            }
+        }
+        Err(e) => println!("❌ Failed to serialize cache: {}", e),
+    }
+}
+
+const WATCH_POLL_INTERVAL: Duration = Duration::from_millis(500);
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(800);
+
+/// Long-running daemon mode: polls the tracked source tree, debounces
+/// bursts of changes, and on each settled batch appends a change event
+/// to the cache, refreshes its file snapshot, and re-renders the
+/// context summary so an attached assistant session sees updated
+/// blockers and pending tasks without re-invoking the script.
+fn cmd_watch() {
+    println!("👀 Watching project tree for changes... (Ctrl+C to stop)");
+    println!();
+
+    let tracked = tracked_paths();
+    if tracked.is_empty() {
+        println!("⚠️  No tracked files found (is this a Git repo?)");
+        return;
+    }
+
+    let mut known: BTreeMap<String, u64> = tracked
+        .iter()
+        .filter_map(|p| current_mtime_secs(p).map(|secs| (p.clone(), secs)))
+        .collect();
+
+    let mut pending_changes: Vec<String> = Vec::new();
+    let mut last_change_at: Option<Instant> = None;
+
+    loop {
+        thread::sleep(WATCH_POLL_INTERVAL);
+
+        let mut changed_now = Vec::new();
+        for path in &tracked {
+            if let Some(secs) = current_mtime_secs(path) {
+                if known.get(path) != Some(&secs) {
+                    known.insert(path.clone(), secs);
+                    changed_now.push(path.clone());
+                }
+            }
+        }
+
+        if !changed_now.is_empty() {
+            pending_changes.extend(changed_now);
+            last_change_at = Some(Instant::now());
+            continue;
+        }
+
+        let debounce_elapsed = last_change_at.is_some_and(|t| t.elapsed() >= WATCH_DEBOUNCE);
+        if debounce_elapsed && !pending_changes.is_empty() {
+            let batch = std::mem::take(&mut pending_changes);
+            on_watch_batch(&batch, &tracked);
+            last_change_at = None;
+        }
+    }
+}
+
+/// Records one debounced batch of changed files: reports drift against
+/// the snapshot recorded at the last session boundary or watch tick,
+/// then appends a `watch_events` entry, refreshes the snapshot manifest,
+/// and re-renders the context summary.
+fn on_watch_batch(batch: &[String], tracked: &[String]) {
+    let mut cache = load_cache_or_default();
+
+    println!("🔔 Change detected:");
+    for path in batch {
+        println!("  - {}", path);
+    }
+    println!();
+
+    // Compare against the *previous* snapshot before it gets overwritten below.
+    check_freshness(&cache);
+
+    if !cache["watch_events"].is_array() {
+        cache["watch_events"] = serde_json::json!([]);
+    }
+    cache["watch_events"].as_array_mut().unwrap().push(serde_json::json!({
+        "at": now_secs(),
+        "paths": batch,
+    }));
+
+    cache["snapshot"] = build_snapshot(tracked);
+
+    save_cache(&cache);
+
+    display_cache(&cache);
+}
+
+/// Lists the tracked source paths to watch, mirroring how an LSP server
+/// scopes itself to a project's open documents rather than the whole
+/// filesystem.
+fn tracked_paths() -> Vec<String> {
+    let Ok(output) = Command::new("git").args(["ls-files"]).output() else {
+        return Vec::new();
+    };
+    if !output.status.success() {
+        return Vec::new();
+    }
+    String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .map(str::to_string)
+        .collect()
+}
+
+/// Compares the cache's recorded `snapshot` (HEAD commit + tracked file
+/// mtimes, written by `/save-session-cache`) against the current state of
+/// the working tree, and prints a banner naming whatever has moved on
+/// since the cache was written.
+fn check_freshness(cache: &serde_json::Value) {
+    let Some(snapshot) = cache.get("snapshot") else {
+        return;
+    };
+
+    let mut changed: Vec<String> = Vec::new();
+
+    let recorded_head = snapshot.get("head_commit").and_then(|v| v.as_str());
+    match (recorded_head, current_head_commit()) {
+        (Some(recorded), Some(current)) if current != recorded => {
+            changed.push("git HEAD has moved".to_string());
+        }
+        _ => {}
+    }
+
+    if let Some(files) = snapshot.get("files").and_then(|v| v.as_object()) {
+        for (path, recorded_mtime) in files {
+            let Some(recorded_secs) = recorded_mtime.as_u64() else {
+                continue;
+            };
+            match current_mtime_secs(path) {
+                Some(current_secs) if current_secs > recorded_secs => {
+                    changed.push(path.clone());
+                }
+                None => {
+                    changed.push(format!("{} (removed)", path));
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if !changed.is_empty() {
+        println!("🔄 Context may be stale:");
+        for entry in &changed {
+            println!("  - {}", entry);
+        }
+        println!();
+    }
+}
+
+fn current_head_commit() -> Option<String> {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn current_mtime_secs(path: &str) -> Option<u64> {
+    let metadata = fs::metadata(path).ok()?;
+    let modified = metadata.modified().ok()?;
+    modified.duration_since(UNIX_EPOCH).ok().map(|d| d.as_secs())
+}
+
+/// Builds the `snapshot` object (`head_commit` + `path -> mtime_secs`
+/// manifest) that gets written into the cache at save time (`begin`,
+/// `end`, and each `watch` tick) so a later read can detect drift via
+/// [`check_freshness`].
+fn build_snapshot(tracked_paths: &[String]) -> serde_json::Value {
+    let head_commit = current_head_commit().unwrap_or_default();
+
+    let mut files: BTreeMap<String, u64> = BTreeMap::new();
+    for path in tracked_paths {
+        if let Some(secs) = current_mtime_secs(path) {
+            files.insert(path.clone(), secs);
+        }
+    }
+
+    serde_json::json!({
+        "head_commit": head_commit,
+        "files": files,
+    })
+}
+
 fn display_cache(cache: &serde_json::Value) {
     // Project info
     if let Some(project) = cache.get("project") {